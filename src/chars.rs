@@ -0,0 +1,150 @@
+use crate::cursor::UTF8_CHAR_WIDTH;
+use crate::{Cursor, Error};
+
+impl<'a> Cursor<'a> {
+    /// Decodes and advances past the next char encoded as UTF-8, if any.
+    ///
+    /// Unlike [Cursor::advance_char], this does not normalize line terminators: `"\r\n"` decodes
+    /// as two separate chars, `'\r'` and `'\n'`, matching `String::from_utf8_lossy`'s behavior.
+    ///
+    /// Returns `Ok(None)` at the end of input. Returns [Error::InvalidCodepoint] if the bytes
+    /// ahead decode to a value that is not a Unicode scalar value (a surrogate half or a value
+    /// above `U+10FFFF`) or to an overlong encoding of one (e.g. a three-byte encoding of a
+    /// codepoint that fits in two bytes), and the same [Error] variants as [Cursor::advance_char]
+    /// if they are not well-formed UTF-8 at all.
+    pub fn next_char(&mut self) -> Result<Option<char>, Error> {
+        let Some(first) = self.peek() else {
+            return Ok(None);
+        };
+
+        let width = UTF8_CHAR_WIDTH[first as usize];
+        if width == 0 {
+            return Err(Error::EncounteredContinuationByte);
+        }
+
+        unsafe { self.advance_unchecked() };
+
+        macro_rules! next {
+            ($e:expr,$i:expr) => {
+                match self.peek() {
+                    None => return Err($e),
+                    // Leave a byte that is not a continuation byte unconsumed, so decoding can
+                    // resume at it.
+                    Some(x) if x & 0b1100_0000 != 0b1000_0000 => return Err($i),
+                    Some(x) => {
+                        unsafe { self.advance_unchecked() };
+                        x
+                    }
+                }
+            };
+        }
+
+        let mut scalar = match width {
+            1 => first as u32,
+            2 => (first & 0b0001_1111) as u32,
+            3 => (first & 0b0000_1111) as u32,
+            4 => (first & 0b0000_0111) as u32,
+            // `width` comes out of `UTF8_CHAR_WIDTH`, which only ever holds 0..=4.
+            _ => unsafe { core::hint::unreachable_unchecked() },
+        };
+
+        match width {
+            1 => {}
+            2 => {
+                let b = next!(Error::Missing2ndOf2, Error::Invalid2ndOf2);
+                scalar = (scalar << 6) | (b & 0b0011_1111) as u32;
+            }
+            3 => {
+                let b1 = next!(Error::Missing2ndOf3, Error::Invalid2ndOf3);
+                let b2 = next!(Error::Missing3rdOf3, Error::Invalid3rdOf3);
+                scalar = (scalar << 6) | (b1 & 0b0011_1111) as u32;
+                scalar = (scalar << 6) | (b2 & 0b0011_1111) as u32;
+            }
+            4 => {
+                let b1 = next!(Error::Missing2ndOf4, Error::Invalid2ndOf4);
+                let b2 = next!(Error::Missing3rdOf4, Error::Invalid3rdOf4);
+                let b3 = next!(Error::Missing4thOf4, Error::Invalid4thOf4);
+                scalar = (scalar << 6) | (b1 & 0b0011_1111) as u32;
+                scalar = (scalar << 6) | (b2 & 0b0011_1111) as u32;
+                scalar = (scalar << 6) | (b3 & 0b0011_1111) as u32;
+            }
+            _ => unsafe { core::hint::unreachable_unchecked() },
+        }
+
+        // Reject overlong encodings: a codepoint that fits in fewer bytes than it was encoded
+        // with is not well-formed UTF-8, even though it decodes to a valid scalar value.
+        let min = match width {
+            1 => 0,
+            2 => 0x80,
+            3 => 0x800,
+            4 => 0x10000,
+            _ => unsafe { core::hint::unreachable_unchecked() },
+        };
+        if scalar < min {
+            return Err(Error::InvalidCodepoint);
+        }
+
+        char::from_u32(scalar).map(Some).ok_or(Error::InvalidCodepoint)
+    }
+
+    /// Returns the number of leading bytes, starting at the cursor, that form well-formed UTF-8.
+    pub fn valid_up_to(&self) -> usize {
+        let base = self.bytes_consumed();
+        let mut cursor = self.clone();
+
+        loop {
+            let before = cursor.clone();
+
+            match cursor.next_char() {
+                Ok(Some(_)) => {}
+                Ok(None) => break,
+                Err(_) => {
+                    cursor = before;
+                    break;
+                }
+            }
+        }
+
+        cursor.bytes_consumed() - base
+    }
+
+    /// Returns an iterator over the `char`s of the cursor's remaining input, replacing invalid
+    /// or truncated UTF-8 sequences with `U+FFFD REPLACEMENT CHARACTER` instead of stopping or
+    /// erroring. See [CharsLossy].
+    #[inline]
+    pub fn chars_lossy(&self) -> CharsLossy<'a> {
+        CharsLossy { cursor: self.clone() }
+    }
+}
+
+/// An iterator over the `char`s of a [Cursor], modeled on `String::from_utf8_lossy`: invalid or
+/// truncated UTF-8 sequences are replaced with `U+FFFD` and the cursor resynchronizes on the
+/// next well-formed char instead of stopping.
+///
+/// Created by [Cursor::chars_lossy].
+pub struct CharsLossy<'a> {
+    cursor: Cursor<'a>,
+}
+
+impl<'a> Iterator for CharsLossy<'a> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        let before = self.cursor.position();
+
+        match self.cursor.next_char() {
+            Ok(Some(c)) => Some(c),
+            Ok(None) => None,
+            Err(_) => {
+                if self.cursor.position() == before {
+                    // The lead byte itself cannot start a UTF-8 sequence (a continuation byte or
+                    // a byte that never appears in UTF-8); nothing was consumed, so skip it by
+                    // hand to guarantee forward progress.
+                    unsafe { self.cursor.advance_unchecked() }
+                }
+
+                Some(char::REPLACEMENT_CHARACTER)
+            }
+        }
+    }
+}
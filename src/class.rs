@@ -0,0 +1,117 @@
+use crate::Cursor;
+
+/// A byte is an ASCII digit (`0`..=`9`).
+pub const DIGIT: u8 = 1 << 0;
+
+/// A byte is an ASCII hex digit (`0`..=`9`, `a`..=`f`, `A`..=`F`).
+pub const HEX: u8 = 1 << 1;
+
+/// A byte may start an identifier (`a`..=`z`, `A`..=`Z`, `_`).
+pub const IDENT_START: u8 = 1 << 2;
+
+/// A byte may continue an identifier, i.e. an [IDENT_START] byte or an ASCII digit.
+pub const IDENT_CONTINUE: u8 = 1 << 3;
+
+/// A byte is ASCII whitespace, see [u8::is_ascii_whitespace].
+pub const WHITESPACE: u8 = 1 << 4;
+
+/// A byte is ASCII punctuation, see [u8::is_ascii_punctuation].
+pub const PUNCT: u8 = 1 << 5;
+
+/// Computes the OR of every class a byte belongs to, used to build [TABLE].
+const fn classify(b: u8) -> u8 {
+    let mut flags = 0;
+
+    if b.is_ascii_digit() {
+        flags |= DIGIT | HEX | IDENT_CONTINUE;
+    } else if matches!(b, b'a'..=b'f' | b'A'..=b'F') {
+        flags |= HEX;
+    }
+
+    if matches!(b, b'a'..=b'z' | b'A'..=b'Z' | b'_') {
+        flags |= IDENT_START | IDENT_CONTINUE;
+    }
+
+    if b.is_ascii_whitespace() {
+        flags |= WHITESPACE;
+    }
+
+    if b.is_ascii_punctuation() {
+        flags |= PUNCT;
+    }
+
+    flags
+}
+
+const fn build_table() -> [u8; 256] {
+    let mut table = [0u8; 256];
+    let mut i = 0;
+    while i < table.len() {
+        table[i] = classify(i as u8);
+        i += 1;
+    }
+    table
+}
+
+/// Lookup table mapping each byte to the OR of the classes ([DIGIT], [HEX], [IDENT_START],
+/// [IDENT_CONTINUE], [WHITESPACE], [PUNCT]) it belongs to.
+static TABLE: [u8; 256] = build_table();
+
+/// Returns whether `b` is an ASCII digit (`0`..=`9`).
+#[inline]
+pub const fn is_digit(b: u8) -> bool {
+    TABLE[b as usize] & DIGIT != 0
+}
+
+/// Returns whether `b` is an ASCII hex digit (`0`..=`9`, `a`..=`f`, `A`..=`F`).
+#[inline]
+pub const fn is_hex(b: u8) -> bool {
+    TABLE[b as usize] & HEX != 0
+}
+
+/// Returns whether `b` may start an identifier.
+#[inline]
+pub const fn is_ident_start(b: u8) -> bool {
+    TABLE[b as usize] & IDENT_START != 0
+}
+
+/// Returns whether `b` may continue an identifier.
+#[inline]
+pub const fn is_ident_continue(b: u8) -> bool {
+    TABLE[b as usize] & IDENT_CONTINUE != 0
+}
+
+/// Returns whether `b` is ASCII whitespace.
+#[inline]
+pub const fn is_whitespace(b: u8) -> bool {
+    TABLE[b as usize] & WHITESPACE != 0
+}
+
+/// Returns whether `b` is ASCII punctuation.
+#[inline]
+pub const fn is_punct(b: u8) -> bool {
+    TABLE[b as usize] & PUNCT != 0
+}
+
+impl<'a> Cursor<'a> {
+    /// Advances while the current byte matches any of `flags`, an OR of the class constants in
+    /// [crate::class].
+    #[inline]
+    pub fn skip_class(&mut self, flags: u8) {
+        loop {
+            match self.peek() {
+                Some(b) if TABLE[b as usize] & flags != 0 => unsafe { self.advance_unchecked() },
+                _ => break,
+            }
+        }
+    }
+
+    /// Returns the run of bytes starting at the current position that match any of `flags`,
+    /// advancing the cursor past it. See [Cursor::skip_class].
+    #[inline]
+    pub fn take_class(&mut self, flags: u8) -> &'a [u8] {
+        let start = self.position();
+        self.skip_class(flags);
+        start.slice_to(self.position())
+    }
+}
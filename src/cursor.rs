@@ -2,43 +2,135 @@ use core::hint::unreachable_unchecked;
 use core::marker::PhantomData;
 use crate::Position;
 
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Types that can be decoded from a fixed-size little-endian or big-endian byte sequence.
+///
+/// This trait is sealed and implemented for all of Rust's built-in integer and
+/// floating-point types. See [Cursor::peek_le], [Cursor::peek_be], [Cursor::next_le] and
+/// [Cursor::next_be].
+pub trait FromBytes: sealed::Sealed + Copy {
+    /// The number of bytes making up `Self`'s encoding.
+    const SIZE: usize;
+
+    /// Decodes `Self` from `bytes`, interpreted as little-endian.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes.len() != Self::SIZE`.
+    fn from_le(bytes: &[u8]) -> Self;
+
+    /// Decodes `Self` from `bytes`, interpreted as big-endian.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes.len() != Self::SIZE`.
+    fn from_be(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_from_bytes {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl FromBytes for $t {
+                const SIZE: usize = size_of::<$t>();
+
+                #[inline]
+                fn from_le(bytes: &[u8]) -> Self {
+                    let mut buf = [0u8; size_of::<$t>()];
+                    buf.copy_from_slice(bytes);
+                    <$t>::from_le_bytes(buf)
+                }
+
+                #[inline]
+                fn from_be(bytes: &[u8]) -> Self {
+                    let mut buf = [0u8; size_of::<$t>()];
+                    buf.copy_from_slice(bytes);
+                    <$t>::from_be_bytes(buf)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_bytes!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize, f32, f64);
+
+impl<'a> Cursor<'a> {
+    /// Peeks the next `N` bytes without advancing the cursor.
+    #[inline]
+    pub fn peek_bytes<const N: usize>(&self) -> Option<[u8; N]> {
+        if self.bytes_remaining() < N {
+            None
+        } else {
+            Some(unsafe { (self.cursor as *const [u8; N]).read_unaligned() })
+        }
+    }
+
+    /// Peeks the next `N` bytes, advancing the cursor past them.
+    #[inline]
+    pub fn next_bytes<const N: usize>(&mut self) -> Option<[u8; N]> {
+        self.peek_bytes::<N>().inspect(|_| unsafe { self.advance_n_unchecked(N) })
+    }
+
+    /// Peeks a [FromBytes] value encoded as little-endian, without advancing the cursor.
+    #[inline]
+    pub fn peek_le<T: FromBytes>(&self) -> Option<T> {
+        if self.bytes_remaining() < T::SIZE {
+            None
+        } else {
+            Some(T::from_le(unsafe { core::slice::from_raw_parts(self.cursor, T::SIZE) }))
+        }
+    }
+
+    /// Peeks a [FromBytes] value encoded as big-endian, without advancing the cursor.
+    #[inline]
+    pub fn peek_be<T: FromBytes>(&self) -> Option<T> {
+        if self.bytes_remaining() < T::SIZE {
+            None
+        } else {
+            Some(T::from_be(unsafe { core::slice::from_raw_parts(self.cursor, T::SIZE) }))
+        }
+    }
+
+    /// Reads a [FromBytes] value encoded as little-endian, advancing the cursor past it.
+    #[inline]
+    pub fn next_le<T: FromBytes>(&mut self) -> Option<T> {
+        self.peek_le::<T>().inspect(|_| unsafe { self.advance_n_unchecked(T::SIZE) })
+    }
+
+    /// Reads a [FromBytes] value encoded as big-endian, advancing the cursor past it.
+    #[inline]
+    pub fn next_be<T: FromBytes>(&mut self) -> Option<T> {
+        self.peek_be::<T>().inspect(|_| unsafe { self.advance_n_unchecked(T::SIZE) })
+    }
+}
+
+/// Thin wrapper generating the named per-width `read_*`/`next_*` methods on top of
+/// [Cursor::peek_le]/[Cursor::peek_be]/[Cursor::next_le]/[Cursor::next_be].
 macro_rules! impl_read_n {
     ($le:ident,$be:ident,$nle:ident,$nbe:ident,$t:tt) => {
         impl<'a> Cursor<'a> {
             #[inline]
             pub fn $le(&self) -> Option<$t> {
-                if self.bytes_remaining() < size_of::<$t>() {
-                    None
-                } else {
-                    Some($t::from_le(unsafe { (self.cursor as *const $t).read_unaligned() }))
-                }
+                self.peek_le::<$t>()
             }
 
             #[inline]
             pub fn $be(&self) -> Option<$t> {
-                if self.bytes_remaining() < size_of::<$t>() {
-                    None
-                } else {
-                    Some($t::from_be(unsafe { (self.cursor as *const $t).read_unaligned() }))
-                }
+                self.peek_be::<$t>()
             }
 
             #[inline]
             pub fn $nle(&mut self) -> Option<$t> {
-                self.$le().inspect(|_| {
-                    unsafe {
-                        self.advance_n_unchecked(size_of::<$t>());
-                    }
-                })
+                self.next_le::<$t>()
             }
 
             #[inline]
-            pub fn $nbe(&self) -> Option<$t> {
-                if self.bytes_remaining() < size_of::<$t>() {
-                    None
-                } else {
-                    Some($t::from_be(unsafe { (self.cursor as *const $t).read_unaligned() }))
-                }
+            pub fn $nbe(&mut self) -> Option<$t> {
+                self.next_be::<$t>()
             }
         }
     };
@@ -102,6 +194,11 @@ pub enum Error {
 
     /// The fourth byte of a four byte sequence is not a continuation byte.
     Invalid4thOf4,
+
+    /// The bytes were structurally valid UTF-8 but did not encode a Unicode scalar value (a
+    /// surrogate half or a value above `U+10FFFF`), or encoded one as an overlong sequence (more
+    /// bytes than the shortest encoding of that value requires).
+    InvalidCodepoint,
 }
 
 impl_read_n!(read_u16_le, read_u16_be, next_u16_le, next_u16_be, u16);
@@ -110,7 +207,7 @@ impl_read_n!(read_u32_le, read_u32_be, next_u32_le, next_u32_be, u32);
 impl_read_n!(read_i32_le, read_i32_be, next_i32_le, next_i32_be, i32);
 impl_read_n!(read_u64_le, read_u64_be, next_u64_le, next_u64_be, u64);
 impl_read_n!(read_i64_le, read_i64_be, next_i64_le, next_i64_be, i64);
-impl_read_n!(read_u128_le, read_u128_be, next_u18_le, next_u128_be, u128);
+impl_read_n!(read_u128_le, read_u128_be, next_u128_le, next_u128_be, u128);
 impl_read_n!(read_i128_le, read_i128_be, next_i128_le, next_i128_be, i128);
 
 // F32 and F64 impls
@@ -162,6 +259,9 @@ impl<'a> Cursor<'a> {
     /// Whitespace being defined by [char::is_ascii_whitespace].
     #[inline]
     pub fn skip_ascii_whitespace(&mut self) {
+        #[cfg(feature = "simd")]
+        self.skip_ascii_whitespace_simd();
+
         loop {
             match self.peek() {
                 None => break,
@@ -271,6 +371,12 @@ impl<'a> Cursor<'a> {
         (self.end as usize).saturating_sub(self.cursor as usize)
     }
 
+    /// Returns the unconsumed remainder of the input as a slice.
+    #[inline]
+    pub(crate) fn remaining(&self) -> &'a [u8] {
+        unsafe { core::slice::from_raw_parts(self.cursor, self.bytes_remaining()) }
+    }
+
     /// Checks if the cursor has a next byte.
     #[inline]
     pub fn has_next(&self) -> bool {
@@ -315,6 +421,67 @@ impl<'a> Cursor<'a> {
         }
     }
 
+    /// Checks if the cursor has a previous byte, i.e. it is not at the start.
+    #[inline]
+    pub fn has_prev(&self) -> bool {
+        self.cursor > self.start
+    }
+
+    /// Peeks into the previous byte. Does not move the cursor.
+    #[inline]
+    pub fn peek_prev(&self) -> Option<u8> {
+        if !self.has_prev() {
+            None
+        } else {
+            Some(unsafe { *self.cursor.sub(1) })
+        }
+    }
+
+    /// Moves the cursor back one byte, returning the byte stepped over.
+    #[inline]
+    pub fn prev(&mut self) -> Option<u8> {
+        if !self.has_prev() {
+            return None;
+        }
+
+        unsafe {
+            self.retreat_n_unchecked(1);
+            Some(self.peek_unchecked())
+        }
+    }
+
+    /// Moves the cursor back `n` bytes.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the cursor has at least `n` bytes before it.
+    #[inline]
+    pub unsafe fn retreat_n_unchecked(&mut self, n: usize) {
+        unsafe {
+            self.cursor = self.cursor.sub(n);
+        }
+    }
+
+    /// Moves the cursor back over one char encoded as UTF-8, using the same backward decoding
+    /// logic as [crate::compute_utf8_bytes_len]. Returns whether the cursor moved, i.e. it was
+    /// not already at the start.
+    #[inline]
+    pub fn prev_char(&mut self) -> bool {
+        if !self.has_prev() {
+            return false;
+        }
+
+        let consumed = unsafe { core::slice::from_raw_parts(self.start, self.bytes_consumed()) };
+        let mut index = consumed.len() - 1;
+
+        if matches!(consumed[index], 0x80..=0xBF) {
+            crate::compute_utf8_bytes_len(consumed, &mut index);
+        }
+
+        unsafe { self.retreat_n_unchecked(consumed.len() - index) };
+        true
+    }
+
     /// Advances a UTF-8 character without checking for bounds.
     #[inline]
     pub unsafe fn advance_char_unchecked(&mut self) {
@@ -369,7 +536,7 @@ impl<'a> Cursor<'a> {
     }
 }
 
-const UTF8_CHAR_WIDTH: &[u8; 256] = &[
+pub(crate) const UTF8_CHAR_WIDTH: &[u8; 256] = &[
     // 1  2  3  4  5  6  7  8  9  A  B  C  D  E  F
     1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, // 0
     1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, // 1
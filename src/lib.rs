@@ -3,12 +3,20 @@
 
 //! A crate providing utilities for slice iteration.
 
+mod chars;
+pub mod class;
 mod cursor;
 mod position;
+mod search;
+#[cfg(feature = "simd")]
+mod simd;
 mod tests;
+mod tracking;
 
+pub use chars::*;
 pub use cursor::*;
 pub use position::*;
+pub use tracking::*;
 
 /// Calculates line:col from a source string slice and an offset. CRLF sequences are treated as
 /// one line break.
@@ -80,7 +88,7 @@ fn mask_utf8_byte<const N: u8>(bytes: &[u8], index: usize) -> Option<u8> {
 /// Assumes a byte of the form `0b10XXXXXX` at `bytes[*index]`. Works backwards
 /// from there, i.e. the `bytes[*index]` is the _last_ byte of the encoded
 /// codepoint.
-fn compute_utf8_bytes_len(bytes: &[u8], index: &mut usize) {
+pub(crate) fn compute_utf8_bytes_len(bytes: &[u8], index: &mut usize) {
     match mask_utf8_byte::<2>(bytes, *index) {
         // This is a two-byte UTF-8 encoded codepoint.
         Some(0b110_00000) => {
@@ -95,7 +103,7 @@ fn compute_utf8_bytes_len(bytes: &[u8], index: &mut usize) {
 
     match mask_utf8_byte::<3>(bytes, *index) {
         // This is a three-byte UTF-8 encoded codepoint.
-        Some(0b1110_000) => {
+        Some(0b1110_0000) => {
             *index -= 2;
             return;
         }
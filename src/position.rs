@@ -12,6 +12,16 @@ impl<'a> Position<'a> {
         Self(ptr, PhantomData)
     }
 
+    /// Returns a new position advanced by `offset` bytes.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `offset` bytes from `self` stay within the original slice.
+    #[inline]
+    pub(crate) unsafe fn add(self, offset: usize) -> Self {
+        Self(unsafe { self.0.add(offset) }, PhantomData)
+    }
+
     /// Returns the slice bounded by `self` and the parameter `next`.
     ///
     /// **Panics if `self > next`**.
@@ -0,0 +1,195 @@
+use core::cmp::Ordering;
+use crate::{Cursor, Position};
+
+/// Finds the first occurrence of a single byte using a word-at-a-time SWAR scan, falling back
+/// to a scalar scan for the final partial word.
+fn memchr(haystack: &[u8], needle: u8) -> Option<usize> {
+    const WORD: usize = size_of::<usize>();
+    const LO: usize = usize::from_ne_bytes([0x01; WORD]);
+    const HI: usize = usize::from_ne_bytes([0x80; WORD]);
+
+    let repeated = usize::from_ne_bytes([needle; WORD]);
+
+    let mut i = 0;
+    while i + WORD <= haystack.len() {
+        let chunk = usize::from_ne_bytes(haystack[i..i + WORD].try_into().unwrap());
+        let xor = chunk ^ repeated;
+
+        // The classic SWAR `has_zero` trick: flags (approximately) a zero byte per lane.
+        if xor.wrapping_sub(LO) & !xor & HI != 0 {
+            break;
+        }
+
+        i += WORD;
+    }
+
+    haystack[i..].iter().position(|&b| b == needle).map(|pos| i + pos)
+}
+
+/// Computes the maximal suffix of `needle` under the byte ordering given by `cmp`, via the
+/// Crochemore-Perrin algorithm. Returns `(position, period)`, where `position` may be `-1` if
+/// the whole needle is the maximal suffix.
+fn maximal_suffix(needle: &[u8], cmp: impl Fn(u8, u8) -> Ordering) -> (isize, usize) {
+    let m = needle.len() as isize;
+
+    let mut suffix_pos: isize = -1;
+    let mut j: isize = 0;
+    let mut k: isize = 1;
+    let mut period: isize = 1;
+
+    while j + k < m {
+        let a = needle[(j + k) as usize];
+        let b = needle[(suffix_pos + k) as usize];
+
+        match cmp(a, b) {
+            Ordering::Less => {
+                j += k;
+                k = 1;
+                period = j - suffix_pos;
+            }
+            Ordering::Equal => {
+                if k != period {
+                    k += 1;
+                } else {
+                    j += period;
+                    k = 1;
+                }
+            }
+            Ordering::Greater => {
+                suffix_pos = j;
+                j = suffix_pos + 1;
+                k = 1;
+                period = 1;
+            }
+        }
+    }
+
+    (suffix_pos, period as usize)
+}
+
+/// Finds the first occurrence of `needle` (at least 2 bytes) in `haystack` using the two-way
+/// string-matching algorithm (Crochemore & Perrin): a critical factorization of the needle is
+/// computed once, then the haystack is scanned matching the right half left-to-right and,
+/// on a full right-half match, the left half right-to-left, shifting by the needle's period
+/// (and remembering the previously-matched prefix length) when the period divides the needle,
+/// or by the full match length otherwise.
+fn two_way_find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    if needle.len() > haystack.len() {
+        return None;
+    }
+
+    let (i, p) = maximal_suffix(needle, |a, b| a.cmp(&b));
+    let (j, q) = maximal_suffix(needle, |a, b| b.cmp(&a));
+    let (ell, period) = if i > j { (i, p as isize) } else { (j, q as isize) };
+
+    let ell_len = (ell + 1) as usize;
+    let periodic = ell_len == 0
+        || (period as usize + ell_len <= needle.len()
+            && needle[..ell_len] == needle[period as usize..period as usize + ell_len]);
+
+    let x = needle;
+    let y = haystack;
+    let m = x.len() as isize;
+    let n = y.len() as isize;
+    let mut pos: isize = 0;
+
+    if periodic {
+        let mut memory: isize = 0;
+
+        while pos <= n - m {
+            let mut k = core::cmp::max(ell + 1, memory);
+            while k < m && x[k as usize] == y[(pos + k) as usize] {
+                k += 1;
+            }
+
+            if k >= m {
+                let mut k = ell;
+                while k >= memory && x[k as usize] == y[(pos + k) as usize] {
+                    k -= 1;
+                }
+
+                if k < memory {
+                    return Some(pos as usize);
+                }
+
+                pos += period;
+                memory = m - period;
+            } else {
+                pos += k - ell;
+                memory = 0;
+            }
+        }
+    } else {
+        let shift = core::cmp::max(ell + 1, m - ell - 1) + 1;
+
+        while pos <= n - m {
+            let mut k = ell + 1;
+            while k < m && x[k as usize] == y[(pos + k) as usize] {
+                k += 1;
+            }
+
+            if k >= m {
+                let mut k = ell;
+                while k >= 0 && x[k as usize] == y[(pos + k) as usize] {
+                    k -= 1;
+                }
+
+                if k < 0 {
+                    return Some(pos as usize);
+                }
+
+                pos += shift;
+            } else {
+                pos += k - ell;
+            }
+        }
+    }
+
+    None
+}
+
+impl<'a> Cursor<'a> {
+    /// Returns the byte offset (from the cursor) of the first occurrence of `needle`, if any.
+    fn search(&self, needle: &[u8]) -> Option<usize> {
+        let remaining = self.remaining();
+
+        match needle {
+            [] => Some(0),
+            [byte] => memchr(remaining, *byte),
+            _ => two_way_find(remaining, needle),
+        }
+    }
+
+    /// Returns the position of the first occurrence of `needle` at or after the cursor, without
+    /// advancing it.
+    #[inline]
+    pub fn find(&self, needle: &[u8]) -> Option<Position<'a>> {
+        self.search(needle).map(|offset| unsafe { self.position().add(offset) })
+    }
+
+    /// Advances the cursor to the start of the first occurrence of `needle`, returning whether
+    /// it was found. If `needle` is not found, the cursor is advanced to the end.
+    #[inline]
+    pub fn skip_until(&mut self, needle: &[u8]) -> bool {
+        match self.search(needle) {
+            Some(offset) => {
+                unsafe { self.advance_n_unchecked(offset) };
+                true
+            }
+            None => {
+                unsafe { self.advance_n_unchecked(self.bytes_remaining()) };
+                false
+            }
+        }
+    }
+
+    /// Returns the bytes from the cursor up to, but excluding, the first occurrence of `needle`,
+    /// advancing the cursor to the start of the match. If `needle` is not found, returns (and
+    /// skips) the remainder of the input.
+    #[inline]
+    pub fn take_until(&mut self, needle: &[u8]) -> &'a [u8] {
+        let start = self.position();
+        self.skip_until(needle);
+        start.slice_to(self.position())
+    }
+}
@@ -0,0 +1,93 @@
+//! Word-at-a-time acceleration for bulk ASCII scanning, gated behind the `simd` feature.
+//!
+//! These fast paths process a `usize`-sized chunk per bounds check, reading each chunk with an
+//! unaligned load and falling back to the scalar, byte-at-a-time loop for the final partial
+//! chunk. The whitespace path pre-filters each chunk with the classic SWAR
+//! (SIMD-within-a-register) bit tricks; [Cursor::skip_while_ascii] looks up every byte of a
+//! chunk in its table. Results are identical to the scalar loops; this only changes how fast
+//! bulk whitespace/class runs are skipped.
+
+use crate::Cursor;
+
+const WORD: usize = size_of::<usize>();
+const LO: usize = usize::from_ne_bytes([0x01; WORD]);
+const HI: usize = usize::from_ne_bytes([0x80; WORD]);
+
+#[inline]
+const fn broadcast(b: u8) -> usize {
+    usize::from_ne_bytes([b; WORD])
+}
+
+/// Approximate per-byte mask (the high bit of a lane is set if that byte was zero), the classic
+/// SWAR `has_zero` trick.
+#[inline]
+const fn zero_mask(word: usize) -> usize {
+    word.wrapping_sub(LO) & !word & HI
+}
+
+/// Per-byte mask where lanes equal to `b` are flagged.
+#[inline]
+const fn eq_mask(word: usize, b: u8) -> usize {
+    zero_mask(word ^ broadcast(b))
+}
+
+/// Returns whether every byte in `word` is *plausibly* ASCII whitespace, see
+/// [u8::is_ascii_whitespace].
+///
+/// This is a fast pre-filter, not an exact test: `eq_mask`'s `has_zero` trick guarantees it never
+/// misses a real match (no false negatives), but a borrow from one matching lane can spuriously
+/// flag an unrelated lane too, so a `true` result must still be confirmed byte-by-byte before
+/// acting on it.
+#[inline]
+const fn word_is_ascii_whitespace(word: usize) -> bool {
+    const WHITESPACE: [u8; 6] = [b' ', b'\t', b'\n', 0x0B, 0x0C, b'\r'];
+
+    let mut mask = 0;
+    let mut i = 0;
+    while i < WHITESPACE.len() {
+        mask |= eq_mask(word, WHITESPACE[i]);
+        i += 1;
+    }
+
+    mask == HI
+}
+
+impl<'a> Cursor<'a> {
+    /// Advances while the current byte matches any of `flags` in `table` (see [crate::class]),
+    /// amortizing the end-of-input check across `usize`-sized chunks.
+    pub fn skip_while_ascii(&mut self, table: &[u8; 256], flags: u8) {
+        while self.bytes_remaining() >= WORD {
+            let chunk = self.remaining();
+            let matched = chunk[..WORD].iter().take_while(|&&b| table[b as usize] & flags != 0).count();
+
+            if matched < WORD {
+                unsafe { self.advance_n_unchecked(matched) }
+                return;
+            }
+
+            unsafe { self.advance_n_unchecked(WORD) }
+        }
+
+        loop {
+            match self.peek() {
+                Some(b) if table[b as usize] & flags != 0 => unsafe { self.advance_unchecked() },
+                _ => break,
+            }
+        }
+    }
+
+    /// Fast path for [Cursor::skip_ascii_whitespace], scanning a `usize`-sized chunk at a time.
+    pub(crate) fn skip_ascii_whitespace_simd(&mut self) {
+        while self.bytes_remaining() >= WORD {
+            let word = unsafe { (self.remaining().as_ptr() as *const usize).read_unaligned() };
+
+            // `word_is_ascii_whitespace` is only a pre-filter (see its doc comment), so a chunk it
+            // flags as all-whitespace still needs a scalar re-check before it's skipped wholesale.
+            if !word_is_ascii_whitespace(word) || !self.remaining()[..WORD].iter().all(u8::is_ascii_whitespace) {
+                break;
+            }
+
+            unsafe { self.advance_n_unchecked(WORD) }
+        }
+    }
+}
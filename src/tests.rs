@@ -1,6 +1,7 @@
 #![cfg(test)]
 
-use crate::Cursor;
+use crate::class;
+use crate::{Cursor, TrackingCursor};
 
 #[test]
 fn skip_ascii_whitespace() {
@@ -124,4 +125,388 @@ fn advance_char() {
     
     cursor.advance_char().unwrap();
     assert_eq!(cursor.peek(), None);
+}
+
+#[cfg(feature = "simd")]
+#[test]
+fn skip_ascii_whitespace_word_boundary() {
+    // Exercises the word-at-a-time fast path plus its scalar tail.
+    let mut cursor = Cursor::new(b"                \t\t\tx");
+    cursor.skip_ascii_whitespace();
+    assert_eq!(cursor.peek(), Some(b'x'));
+}
+
+#[cfg(feature = "simd")]
+#[test]
+fn skip_ascii_whitespace_rejects_cross_lane_false_positive() {
+    // Regression test: `word_is_ascii_whitespace`'s SWAR pre-filter reports this word as
+    // all-whitespace because the `0x09` match at lane 0 borrows into lane 1, spuriously flagging
+    // the `0x08` (backspace, not whitespace) byte there too; the fast path must not skip it.
+    let mut cursor = Cursor::new(b"\x09\x08\x09\x09\x09\x09\x09\x09x");
+    cursor.skip_ascii_whitespace();
+    assert_eq!(cursor.peek(), Some(0x08));
+}
+
+#[cfg(feature = "simd")]
+#[test]
+fn skip_while_ascii() {
+    let mut table = [0u8; 256];
+    let mut i = 0;
+    while i < table.len() {
+        if (i as u8).is_ascii_digit() {
+            table[i] = 1;
+        }
+        i += 1;
+    }
+
+    let mut cursor = Cursor::new(b"0123456789012345abc");
+    cursor.skip_while_ascii(&table, 1);
+    assert_eq!(cursor.peek(), Some(b'a'));
+}
+
+#[test]
+fn next_char() {
+    let mut cursor = Cursor::new("A€".as_bytes());
+    assert_eq!(cursor.next_char(), Ok(Some('A')));
+    assert_eq!(cursor.next_char(), Ok(Some('€')));
+    assert_eq!(cursor.next_char(), Ok(None));
+}
+
+#[test]
+fn next_char_invalid() {
+    let mut cursor = Cursor::new(&[0x80]);
+    assert_eq!(cursor.next_char(), Err(crate::Error::EncounteredContinuationByte));
+}
+
+#[test]
+fn next_char_invalid_codepoint() {
+    // `\xED\xA0\x80` is structurally valid three-byte UTF-8 but encodes U+D800, a surrogate half.
+    let mut cursor = Cursor::new(b"\xED\xA0\x80");
+    assert_eq!(cursor.next_char(), Err(crate::Error::InvalidCodepoint));
+
+    // `\xF4\x90\x80\x80` is structurally valid four-byte UTF-8 but encodes U+110000, above
+    // the U+10FFFF maximum.
+    let mut cursor = Cursor::new(b"\xF4\x90\x80\x80");
+    assert_eq!(cursor.next_char(), Err(crate::Error::InvalidCodepoint));
+}
+
+#[test]
+fn next_char_rejects_overlong_encodings() {
+    // `\xE0\x80\x80` is structurally valid three-byte UTF-8 but overlong-encodes U+0000, which
+    // fits in a single byte.
+    let mut cursor = Cursor::new(b"\xE0\x80\x80");
+    assert_eq!(cursor.next_char(), Err(crate::Error::InvalidCodepoint));
+
+    // `\xF0\x80\x80\x80` overlong-encodes U+0000 in four bytes instead of one.
+    let mut cursor = Cursor::new(b"\xF0\x80\x80\x80");
+    assert_eq!(cursor.next_char(), Err(crate::Error::InvalidCodepoint));
+}
+
+#[test]
+fn valid_up_to_rejects_overlong_encodings() {
+    let cursor = Cursor::new(b"a\xE0\x80\x80b");
+    assert_eq!(cursor.valid_up_to(), 1);
+}
+
+#[test]
+fn next_char_does_not_normalize_crlf() {
+    let mut cursor = Cursor::new(b"a\r\nb");
+    assert_eq!(cursor.next_char(), Ok(Some('a')));
+    assert_eq!(cursor.next_char(), Ok(Some('\r')));
+    assert_eq!(cursor.next_char(), Ok(Some('\n')));
+    assert_eq!(cursor.next_char(), Ok(Some('b')));
+    assert_eq!(cursor.next_char(), Ok(None));
+}
+
+#[test]
+fn valid_up_to() {
+    let cursor = Cursor::new(b"ab\xFFcd");
+    assert_eq!(cursor.valid_up_to(), 2);
+
+    let cursor = Cursor::new("héllo".as_bytes());
+    assert_eq!(cursor.valid_up_to(), "héllo".len());
+}
+
+#[test]
+fn chars_lossy() {
+    let cursor = Cursor::new(b"a\xFFb");
+    let mut chars = cursor.chars_lossy();
+
+    assert_eq!(chars.next(), Some('a'));
+    assert_eq!(chars.next(), Some(char::REPLACEMENT_CHARACTER));
+    assert_eq!(chars.next(), Some('b'));
+    assert_eq!(chars.next(), None);
+}
+
+#[test]
+fn chars_lossy_truncated_multibyte() {
+    // `\xE2\x82` is the first two bytes of '€' (\xE2\x82\xAC) with the third byte missing.
+    let cursor = Cursor::new(b"a\xE2\x82");
+    let mut chars = cursor.chars_lossy();
+
+    assert_eq!(chars.next(), Some('a'));
+    assert_eq!(chars.next(), Some(char::REPLACEMENT_CHARACTER));
+    assert_eq!(chars.next(), None);
+}
+
+#[test]
+fn chars_lossy_invalid_codepoint() {
+    // `\xED\xA0\x80` is structurally valid UTF-8 encoding a surrogate half; unlike a genuine
+    // decode failure partway through the input, it must not be mistaken for end-of-input and
+    // swallow the byte that follows it.
+    let cursor = Cursor::new(b"\xED\xA0\x80x");
+    let mut chars = cursor.chars_lossy();
+
+    assert_eq!(chars.next(), Some(char::REPLACEMENT_CHARACTER));
+    assert_eq!(chars.next(), Some('x'));
+    assert_eq!(chars.next(), None);
+}
+
+#[test]
+fn chars_lossy_preserves_crlf() {
+    let cursor = Cursor::new(b"a\r\nb");
+    let mut chars = cursor.chars_lossy();
+
+    assert_eq!(chars.next(), Some('a'));
+    assert_eq!(chars.next(), Some('\r'));
+    assert_eq!(chars.next(), Some('\n'));
+    assert_eq!(chars.next(), Some('b'));
+    assert_eq!(chars.next(), None);
+}
+
+#[test]
+fn find_single_byte() {
+    let cursor = Cursor::new(b"hello, world");
+    let position = cursor.find(b",").unwrap();
+    assert_eq!(cursor.position().slice_to(position), b"hello");
+
+    assert!(cursor.find(b"?").is_none());
+}
+
+#[test]
+fn find_multi_byte() {
+    let cursor = Cursor::new(b"the quick brown fox");
+    let position = cursor.find(b"brown").unwrap();
+    assert_eq!(cursor.position().slice_to(position), b"the quick ");
+
+    assert!(cursor.find(b"slow").is_none());
+}
+
+#[test]
+fn find_in_periodic_haystack() {
+    let cursor = Cursor::new(b"abababX");
+    let position = cursor.find(b"babX").unwrap();
+    assert_eq!(cursor.position().slice_to(position), b"aba");
+}
+
+#[test]
+fn find_repeating_needle() {
+    // Regression test: the periodic branch of the two-way matcher used to mis-shift on needles
+    // like "aa" that repeat a short period, reporting a match one byte too early.
+    let cursor = Cursor::new(b"caa");
+    let position = cursor.find(b"aa").unwrap();
+    assert_eq!(cursor.position().slice_to(position), b"c");
+}
+
+#[test]
+fn find_periodic_needle_not_present() {
+    // Regression test: a periodic needle ("cccc") that never actually occurs used to produce a
+    // false positive once the haystack contained a long enough run of matching suffix bytes.
+    let cursor = Cursor::new(b"bbbcccbbacacba");
+    assert!(cursor.find(b"cccc").is_none());
+}
+
+#[test]
+fn skip_until() {
+    let mut cursor = Cursor::new(b"key: value");
+    assert!(cursor.skip_until(b": "));
+    assert_eq!(cursor.peek(), Some(b':'));
+
+    let mut cursor = Cursor::new(b"no delimiter here");
+    assert!(!cursor.skip_until(b"xyz"));
+    assert_eq!(cursor.peek(), None);
+}
+
+#[test]
+fn take_until() {
+    let mut cursor = Cursor::new(b"key: value");
+    assert_eq!(cursor.take_until(b": "), b"key");
+    assert_eq!(cursor.peek(), Some(b':'));
+
+    let mut cursor = Cursor::new(b"no delimiter here");
+    assert_eq!(cursor.take_until(b"xyz"), b"no delimiter here");
+    assert_eq!(cursor.peek(), None);
+}
+
+#[test]
+fn tracking_cursor_next() {
+    let mut cursor = TrackingCursor::new(b"ab\ncd");
+
+    assert_eq!(cursor.line_col(), (0, 0));
+    cursor.next();
+    assert_eq!(cursor.line_col(), (0, 1));
+    cursor.next();
+    assert_eq!(cursor.line_col(), (0, 2));
+    cursor.next();
+    assert_eq!(cursor.line_col(), (1, 0));
+    cursor.next();
+    assert_eq!(cursor.line_col(), (1, 1));
+}
+
+#[test]
+fn tracking_cursor_crlf() {
+    let mut cursor = TrackingCursor::new(b"a\r\nb");
+
+    cursor.next();
+    assert_eq!(cursor.line_col(), (0, 1));
+    cursor.next();
+    assert_eq!(cursor.line_col(), (1, 0));
+    cursor.next();
+    assert_eq!(cursor.line_col(), (1, 0));
+    cursor.next();
+    assert_eq!(cursor.line_col(), (1, 1));
+}
+
+#[test]
+fn tracking_cursor_next_lfn() {
+    let mut cursor = TrackingCursor::new(b"a\r\nb");
+
+    cursor.next_lfn();
+    assert_eq!(cursor.line_col(), (0, 1));
+    cursor.next_lfn();
+    assert_eq!(cursor.line_col(), (1, 0));
+    cursor.next_lfn();
+    assert_eq!(cursor.line_col(), (1, 1));
+}
+
+#[test]
+fn tracking_cursor_next_counts_codepoints_not_bytes() {
+    // Regression test: stepping a multi-byte UTF-8 char one byte at a time via `next` used to
+    // advance the column once per byte instead of once per codepoint.
+    let mut cursor = TrackingCursor::new("é".as_bytes());
+    assert_eq!(cursor.line_col(), (0, 0));
+
+    cursor.next();
+    assert_eq!(cursor.line_col(), (0, 1));
+
+    cursor.next();
+    assert_eq!(cursor.line_col(), (0, 1));
+}
+
+#[test]
+fn tracking_cursor_deref() {
+    let cursor = TrackingCursor::new(b"ab");
+    assert_eq!(cursor.bytes_remaining(), 2);
+}
+
+#[test]
+fn prev_and_peek_prev() {
+    let mut cursor = Cursor::new(b"AB");
+    assert_eq!(cursor.peek_prev(), None);
+    assert_eq!(cursor.prev(), None);
+
+    cursor.advance();
+    cursor.advance();
+
+    assert_eq!(cursor.peek_prev(), Some(b'B'));
+    assert_eq!(cursor.prev(), Some(b'B'));
+    assert_eq!(cursor.peek_prev(), Some(b'A'));
+    assert_eq!(cursor.prev(), Some(b'A'));
+    assert_eq!(cursor.prev(), None);
+}
+
+#[test]
+fn prev_char() {
+    let slice = "AB€C".as_bytes();
+    let mut cursor = Cursor::new(slice);
+
+    while cursor.has_next() {
+        cursor.advance_char().unwrap();
+    }
+
+    assert!(cursor.prev_char());
+    assert_eq!(cursor.peek(), Some(b'C'));
+
+    assert!(cursor.prev_char());
+    assert_eq!(cursor.bytes_consumed(), 2);
+
+    assert!(cursor.prev_char());
+    assert_eq!(cursor.bytes_consumed(), 1);
+
+    assert!(cursor.prev_char());
+    assert_eq!(cursor.bytes_consumed(), 0);
+
+    assert!(!cursor.prev_char());
+}
+
+#[test]
+fn peek_and_next_bytes() {
+    let mut cursor = Cursor::new(b"abcdef");
+
+    assert_eq!(cursor.peek_bytes::<3>(), Some(*b"abc"));
+    assert_eq!(cursor.next_bytes::<3>(), Some(*b"abc"));
+    assert_eq!(cursor.next_bytes::<3>(), Some(*b"def"));
+    assert_eq!(cursor.next_bytes::<1>(), None);
+}
+
+#[test]
+fn next_le_be_generic() {
+    let mut cursor = Cursor::new(&[0x01, 0x00, 0x00, 0x00]);
+    assert_eq!(cursor.next_le::<u32>(), Some(1));
+
+    let mut cursor = Cursor::new(&[0x00, 0x00, 0x00, 0x01]);
+    assert_eq!(cursor.next_be::<u32>(), Some(1));
+}
+
+#[test]
+fn next_u32_be_advances() {
+    let mut cursor = Cursor::new(&[0x00, 0x00, 0x00, 0x01, 0xFF]);
+    assert_eq!(cursor.next_u32_be(), Some(1));
+    assert_eq!(cursor.peek(), Some(0xFF));
+}
+
+#[test]
+fn next_u128_le_advances() {
+    // Regression test: `impl_read_n!`'s u128 invocation named this method `next_u18_le`, a typo
+    // that left `next_u128_le` nonexistent.
+    let mut cursor = Cursor::new(&[1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xFF]);
+    assert_eq!(cursor.next_u128_le(), Some(1));
+    assert_eq!(cursor.peek(), Some(0xFF));
+}
+
+#[test]
+fn class_predicates() {
+    assert!(class::is_digit(b'7'));
+    assert!(!class::is_digit(b'a'));
+
+    assert!(class::is_hex(b'7'));
+    assert!(class::is_hex(b'f'));
+    assert!(class::is_hex(b'F'));
+    assert!(!class::is_hex(b'g'));
+
+    assert!(class::is_ident_start(b'_'));
+    assert!(class::is_ident_start(b'a'));
+    assert!(!class::is_ident_start(b'7'));
+
+    assert!(class::is_ident_continue(b'7'));
+    assert!(class::is_ident_continue(b'_'));
+
+    assert!(class::is_whitespace(b' '));
+    assert!(!class::is_whitespace(b'a'));
+
+    assert!(class::is_punct(b'.'));
+    assert!(!class::is_punct(b'a'));
+}
+
+#[test]
+fn skip_class() {
+    let mut cursor = Cursor::new(b"abc123 rest");
+
+    assert_eq!(
+        cursor.take_class(class::IDENT_START | class::IDENT_CONTINUE),
+        b"abc123"
+    );
+
+    cursor.skip_class(class::WHITESPACE);
+    assert_eq!(cursor.peek(), Some(b'r'));
 }
\ No newline at end of file
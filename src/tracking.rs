@@ -0,0 +1,120 @@
+use core::ops::Deref;
+use crate::{Cursor, Error};
+
+/// A [Cursor] that incrementally tracks its line and column as it advances.
+///
+/// Unlike [crate::get_lines_and_columns], which recomputes the location from scratch by
+/// rescanning the consumed prefix, [TrackingCursor] keeps a running `(line, column)` pair up to
+/// date in O(1) per call to [TrackingCursor::next], [TrackingCursor::next_lfn] or
+/// [TrackingCursor::advance_char]. CRLF sequences count as a single line break and columns count
+/// UTF-8 codepoints, matching the semantics documented on [crate::get_lines_and_columns].
+///
+/// The `&self` methods of [Cursor] (peeking, [Cursor::position], ...) are available through
+/// [Deref]. There is no `DerefMut`, so the cursor advances only through the methods above.
+#[derive(Clone, PartialEq, Eq)]
+pub struct TrackingCursor<'a> {
+    cursor: Cursor<'a>,
+    line: usize,
+    column: usize,
+    /// Set right after tracking a `\r`, so a following `\n` from a split CRLF isn't counted as
+    /// a second line break.
+    pending_crlf: bool,
+}
+
+impl<'a> TrackingCursor<'a> {
+    /// Constructs a new [TrackingCursor] from a slice, starting at line 0, column 0.
+    #[inline]
+    pub const fn new(slice: &'a [u8]) -> Self {
+        Self {
+            cursor: Cursor::new(slice),
+            line: 0,
+            column: 0,
+            pending_crlf: false,
+        }
+    }
+
+    /// Returns the current `(line, column)`, both 0-indexed.
+    #[inline]
+    pub const fn line_col(&self) -> (usize, usize) {
+        (self.line, self.column)
+    }
+
+    /// See [Cursor::next]. Updates [TrackingCursor::line_col].
+    #[inline]
+    pub fn next(&mut self) -> Option<u8> {
+        let byte = self.cursor.next()?;
+        self.track(byte);
+        Some(byte)
+    }
+
+    /// See [Cursor::next_lfn]. Updates [TrackingCursor::line_col].
+    #[inline]
+    pub fn next_lfn(&mut self) -> Option<u8> {
+        let first = self.cursor.peek();
+        let byte = self.cursor.next_lfn()?;
+        if first == Some(b'\r') {
+            // This `\n` was mapped from a `\r`, not the second half of a split CRLF.
+            self.pending_crlf = false;
+        }
+        self.track(byte);
+        Some(byte)
+    }
+
+    /// See [Cursor::advance_char]. Updates [TrackingCursor::line_col].
+    #[inline]
+    pub fn advance_char(&mut self) -> Result<(), Error> {
+        let first = self.cursor.peek();
+        self.cursor.advance_char()?;
+
+        let split_crlf = self.pending_crlf && first == Some(b'\n');
+        self.pending_crlf = false;
+        match first {
+            // Second half of a `\r\n` pair already counted when the `\r` was tracked.
+            _ if split_crlf => {}
+            Some(b'\r') | Some(b'\n') => {
+                self.line += 1;
+                self.column = 0;
+            }
+            Some(_) => self.column += 1,
+            None => {}
+        }
+
+        Ok(())
+    }
+
+    /// Updates `line`/`column` for a single raw byte stepped over by [Cursor::next].
+    fn track(&mut self, byte: u8) {
+        if self.pending_crlf {
+            self.pending_crlf = false;
+            if byte == b'\n' {
+                // Second half of a `\r\n` pair already counted when the `\r` was tracked.
+                return;
+            }
+        }
+
+        match byte {
+            b'\r' => {
+                self.line += 1;
+                self.column = 0;
+                self.pending_crlf = true;
+            }
+            b'\n' => {
+                self.line += 1;
+                self.column = 0;
+            }
+            // A UTF-8 continuation byte; the column was already advanced for the lead byte of
+            // this codepoint, so it must not be counted again here.
+            0x80..=0xBF => {}
+            _ => self.column += 1,
+        }
+    }
+}
+
+impl<'a> Deref for TrackingCursor<'a> {
+    type Target = Cursor<'a>;
+
+    #[inline]
+    fn deref(&self) -> &Cursor<'a> {
+        &self.cursor
+    }
+}